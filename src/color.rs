@@ -3,6 +3,8 @@ use image::Rgb;
 use std::primitive::f64;
 
 /// Color represented using the red-yellow-blue subtractive color model.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
 pub struct Ryb<T: Primitive>(pub [T; 3]);
 
 pub const BLACK: Ryb<f64> = Ryb([1.0, 1.0, 1.0]);
@@ -32,6 +34,15 @@ impl Component for f32 {
     }
 }
 
+impl Component for f64 {
+    fn to_f64(self) -> f64 {
+        self
+    }
+    fn from_f64(x: f64) -> f64 {
+        x
+    }
+}
+
 macro_rules! derive_scaling_component {
     ($type: ty) => {
         impl Component for $type {
@@ -51,6 +62,26 @@ derive_scaling_component!(u16);
 derive_scaling_component!(u32);
 derive_scaling_component!(u64);
 
+/// Decode a gamma-encoded sRGB channel value (in `[0, 1]`) to linear light,
+/// per the sRGB electro-optical transfer function.
+fn srgb_to_linear(c: f64) -> f64 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Encode a linear-light channel value (in `[0, 1]`) as gamma-encoded sRGB,
+/// the inverse of [`srgb_to_linear`].
+fn linear_to_srgb(c: f64) -> f64 {
+    if c <= 0.0031308 {
+        12.92 * c
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    }
+}
+
 impl<T: Component> Ryb<T> {
     /// Create a new `Ryb` from an array of red, yellow, and blue components.
     pub fn new(v: [T; 3]) -> Ryb<T> {
@@ -104,6 +135,19 @@ impl<T: Component> Ryb<T> {
         ])
     }
 
+    /// Like [`new_rgb`], but treats the input as gamma-encoded sRGB, as
+    /// decoded from a PNG or JPEG, rather than linear RGB, applying the
+    /// sRGB electro-optical transfer function before converting to RYB.
+    ///
+    /// [`new_rgb`]: Ryb::new_rgb
+    pub fn new_rgb_srgb(Rgb([r_srgb, g_srgb, b_srgb]): Rgb<T>) -> Ryb<T> {
+        Ryb::new_rgb(Rgb([
+            Component::from_f64(srgb_to_linear(r_srgb.to_f64())),
+            Component::from_f64(srgb_to_linear(g_srgb.to_f64())),
+            Component::from_f64(srgb_to_linear(b_srgb.to_f64())),
+        ]))
+    }
+
     /// Convert to the RGB representation.
     pub fn rgb(&self) -> Rgb<T> {
         let Ryb([r1_ryb, y1_ryb, b1_ryb]) = *self;
@@ -140,9 +184,222 @@ impl<T: Component> Ryb<T> {
             Component::from_f64(b0_ryb),
         ])
     }
+
+    /// Like [`rgb`], but gamma-encodes the result as sRGB, the inverse of
+    /// [`new_rgb_srgb`], instead of returning linear RGB.
+    ///
+    /// [`rgb`]: Ryb::rgb
+    /// [`new_rgb_srgb`]: Ryb::new_rgb_srgb
+    pub fn rgb_srgb(&self) -> Rgb<T> {
+        let Rgb([r_rgb, g_rgb, b_rgb]) = self.rgb();
+
+        Rgb([
+            Component::from_f64(linear_to_srgb(r_rgb.to_f64())),
+            Component::from_f64(linear_to_srgb(g_rgb.to_f64())),
+            Component::from_f64(linear_to_srgb(b_rgb.to_f64())),
+        ])
+    }
+
+    /// Convert to the RGB representation using the Gosset–Chen trilinear
+    /// interpolation scheme, an alternative to the analytic [`rgb`] that
+    /// tends to give warmer, more paint-like greens and oranges.
+    ///
+    /// [`rgb`]: Ryb::rgb
+    pub fn rgb_trilinear(&self) -> Rgb<T> {
+        self.rgb_trilinear_with_corners(&GOSSET_CHEN_CORNERS)
+    }
+
+    /// Like [`rgb_trilinear`], but with a custom corner table, for callers
+    /// who want to tune the reference RGB values for a custom palette.
+    ///
+    /// [`rgb_trilinear`]: Ryb::rgb_trilinear
+    pub fn rgb_trilinear_with_corners(&self, corners: &[[f64; 3]; 8]) -> Rgb<T> {
+        let Ryb([r1_ryb, y1_ryb, b1_ryb]) = *self;
+
+        let r_ryb = r1_ryb.to_f64();
+        let y_ryb = y1_ryb.to_f64();
+        let b_ryb = b1_ryb.to_f64();
+
+        let mut rgb = [0.0; 3];
+
+        for (i, corner) in corners.iter().enumerate() {
+            let r_w = if i & 0b100 != 0 { r_ryb } else { 1.0 - r_ryb };
+            let y_w = if i & 0b010 != 0 { y_ryb } else { 1.0 - y_ryb };
+            let b_w = if i & 0b001 != 0 { b_ryb } else { 1.0 - b_ryb };
+            let w = r_w * y_w * b_w;
+
+            for (c, channel) in corner.iter().enumerate() {
+                rgb[c] += w * channel;
+            }
+        }
+
+        Rgb([
+            Component::from_f64(rgb[0].clamp(0.0, 1.0)),
+            Component::from_f64(rgb[1].clamp(0.0, 1.0)),
+            Component::from_f64(rgb[2].clamp(0.0, 1.0)),
+        ])
+    }
 }
 
-/// Mix a collection of weighted colors.
-pub fn mix<T: Component>(_colors: Vec<(T, Ryb<T>)>) -> Ryb<T> {
-    todo!()
+/// The eight reference RGB corners of the unit RYB cube used by
+/// [`Ryb::rgb_trilinear`], indexed by `(r << 2) | (y << 1) | b`, per the
+/// standard Gosset–Chen scheme:
+///
+/// | RYB corner  | RGB              |
+/// | ----------- | ---------------- |
+/// | `(0, 0, 0)` white  | `(1, 1, 1)`       |
+/// | `(0, 0, 1)` blue   | `(0.163, 0.373, 0.6)` |
+/// | `(0, 1, 0)` yellow | `(1, 1, 0)`       |
+/// | `(0, 1, 1)` green  | `(0, 0.66, 0.2)`  |
+/// | `(1, 0, 0)` red    | `(1, 0, 0)`       |
+/// | `(1, 0, 1)` purple | `(0.5, 0, 0.5)`   |
+/// | `(1, 1, 0)` orange | `(1, 0.5, 0)`     |
+/// | `(1, 1, 1)` black  | `(0.2, 0.094, 0)` |
+pub const GOSSET_CHEN_CORNERS: [[f64; 3]; 8] = [
+    [1.0, 1.0, 1.0],
+    [0.163, 0.373, 0.6],
+    [1.0, 1.0, 0.0],
+    [0.0, 0.66, 0.2],
+    [1.0, 0.0, 0.0],
+    [0.5, 0.0, 0.5],
+    [1.0, 0.5, 0.0],
+    [0.2, 0.094, 0.0],
+];
+
+/// Mix a collection of weighted colors by normalizing the weights to sum to
+/// 1 and averaging component-wise in RYB space. Returns `WHITE` if `colors`
+/// is empty or the weights sum to zero.
+pub fn mix<T: Component>(colors: Vec<(T, Ryb<T>)>) -> Ryb<T> {
+    let total_weight: f64 = colors.iter().map(|(w, _)| (*w).to_f64()).sum();
+
+    if total_weight == 0.0 {
+        return Ryb([
+            Component::from_f64(0.0),
+            Component::from_f64(0.0),
+            Component::from_f64(0.0),
+        ]);
+    }
+
+    let mut r = 0.0;
+    let mut y = 0.0;
+    let mut b = 0.0;
+
+    for (w, Ryb([r_i, y_i, b_i])) in colors {
+        let weight = w.to_f64() / total_weight;
+        r += weight * r_i.to_f64();
+        y += weight * y_i.to_f64();
+        b += weight * b_i.to_f64();
+    }
+
+    Ryb([
+        Component::from_f64(r.clamp(0.0, 1.0)),
+        Component::from_f64(y.clamp(0.0, 1.0)),
+        Component::from_f64(b.clamp(0.0, 1.0)),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mix_canonical_artist_mixes() {
+        let Ryb([r, y, b]) = mix(vec![(1.0, RED), (1.0, YELLOW)]);
+        assert!((r - 0.5).abs() < 1e-9);
+        assert!((y - 0.5).abs() < 1e-9);
+        assert!(b.abs() < 1e-9);
+
+        let Ryb([r, y, b]) = mix(vec![(1.0, BLUE), (1.0, YELLOW)]);
+        assert!(r.abs() < 1e-9);
+        assert!((y - 0.5).abs() < 1e-9);
+        assert!((b - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn mix_zero_weight_is_white() {
+        let Ryb([r, y, b]) = mix(vec![(0.0, RED), (0.0, BLUE)]);
+        assert_eq!((r, y, b), (0.0, 0.0, 0.0));
+
+        let Ryb([r, y, b]) = mix::<f64>(vec![]);
+        assert_eq!((r, y, b), (0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn rgb_trilinear_known_points() {
+        let cases: [([f64; 3], [f64; 3]); 8] = [
+            ([0.0, 0.0, 0.0], [1.0, 1.0, 1.0]),
+            ([1.0, 0.0, 0.0], [1.0, 0.0, 0.0]),
+            ([0.0, 1.0, 0.0], [1.0, 1.0, 0.0]),
+            ([0.0, 0.0, 1.0], [0.163, 0.373, 0.6]),
+            ([1.0, 1.0, 0.0], [1.0, 0.5, 0.0]),
+            ([1.0, 0.0, 1.0], [0.5, 0.0, 0.5]),
+            ([0.0, 1.0, 1.0], [0.0, 0.66, 0.2]),
+            ([1.0, 1.0, 1.0], [0.2, 0.094, 0.0]),
+        ];
+
+        for (ryb, expected) in cases {
+            let Rgb([r, g, b]) = Ryb(ryb).rgb_trilinear();
+            assert!((r - expected[0]).abs() < 1e-9);
+            assert!((g - expected[1]).abs() < 1e-9);
+            assert!((b - expected[2]).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn srgb_round_trip() {
+        for c in [0.0, 0.0031308, 0.04045, 0.18, 0.5, 1.0] {
+            let back = linear_to_srgb(srgb_to_linear(c));
+            assert!((back - c).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn new_rgb_srgb_decodes_gamma_before_converting() {
+        // A mid-brightness color is much darker once decoded from
+        // gamma-encoded sRGB to linear light, so `new_rgb_srgb` must produce
+        // a different (darker) RYB result than treating the same bytes as
+        // already-linear `new_rgb` input.
+        let color = Rgb([0.5_f64, 0.3, 0.2]);
+        let linear = Ryb::new_rgb(color);
+        let srgb = Ryb::new_rgb_srgb(color);
+        let Ryb([r1, y1, b1]) = linear;
+        let Ryb([r2, y2, b2]) = srgb;
+        assert!((r1 - r2).abs() > 1e-3 || (y1 - y2).abs() > 1e-3 || (b1 - b2).abs() > 1e-3);
+    }
+
+    #[test]
+    fn rgb_srgb_matches_gamma_encoded_rgb() {
+        for ryb in [RED, YELLOW, BLUE, GREEN] {
+            let Rgb([r, g, b]) = ryb.rgb();
+            let Rgb([r_srgb, g_srgb, b_srgb]) = ryb.rgb_srgb();
+            assert!((r_srgb - linear_to_srgb(r)).abs() < 1e-9);
+            assert!((g_srgb - linear_to_srgb(g)).abs() < 1e-9);
+            assert!((b_srgb - linear_to_srgb(b)).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn rgb_trilinear_with_corners_reproduces_custom_corners() {
+        let corners: [[f64; 3]; 8] = [
+            [0.1, 0.2, 0.3],
+            [0.4, 0.5, 0.6],
+            [0.7, 0.8, 0.9],
+            [0.05, 0.15, 0.25],
+            [0.35, 0.45, 0.55],
+            [0.65, 0.75, 0.85],
+            [0.95, 0.05, 0.15],
+            [0.25, 0.35, 0.45],
+        ];
+
+        for (i, expected) in corners.iter().enumerate() {
+            let r: f64 = if i & 0b100 != 0 { 1.0 } else { 0.0 };
+            let y: f64 = if i & 0b010 != 0 { 1.0 } else { 0.0 };
+            let b: f64 = if i & 0b001 != 0 { 1.0 } else { 0.0 };
+
+            let Rgb([r_out, g_out, b_out]) = Ryb([r, y, b]).rgb_trilinear_with_corners(&corners);
+            assert!((r_out - expected[0]).abs() < 1e-9);
+            assert!((g_out - expected[1]).abs() < 1e-9);
+            assert!((b_out - expected[2]).abs() < 1e-9);
+        }
+    }
 }
@@ -0,0 +1,354 @@
+//! CSS-style color string parsing and formatting.
+
+use std::fmt;
+
+use image::Rgb;
+
+use crate::color::{Component, Ryb};
+
+/// An error produced when parsing a color from a string fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseColorError {
+    /// The input did not match any recognized hex, functional, or named
+    /// color syntax.
+    InvalidFormat(String),
+    /// A numeric channel (in a `rgb()`/`rgba()` functional color) could not
+    /// be parsed as an integer in `0..=255`.
+    InvalidChannel(String),
+}
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseColorError::InvalidFormat(s) => write!(f, "invalid color format: {s:?}"),
+            ParseColorError::InvalidChannel(s) => write!(f, "invalid color channel: {s:?}"),
+        }
+    }
+}
+
+impl std::error::Error for ParseColorError {}
+
+/// CSS/hex 8-bit channels are gamma-encoded sRGB, so this routes through
+/// [`Ryb::new_rgb_srgb`] rather than the linear [`Ryb::new_rgb`].
+fn rgb_bytes<T: Component>(r: u8, g: u8, b: u8) -> Ryb<T> {
+    Ryb::new_rgb_srgb(Rgb([
+        Component::from_f64(r as f64 / 255.0),
+        Component::from_f64(g as f64 / 255.0),
+        Component::from_f64(b as f64 / 255.0),
+    ]))
+}
+
+impl<T: Component> Ryb<T> {
+    /// Parse a `#rgb`, `#rrggbb`, or `#rrggbbaa` hex color string. The `#`
+    /// is required; an alpha channel, if present, is parsed but discarded,
+    /// since `Ryb` has none.
+    pub fn from_hex(s: &str) -> Result<Ryb<T>, ParseColorError> {
+        let hex = s
+            .strip_prefix('#')
+            .ok_or_else(|| ParseColorError::InvalidFormat(s.to_string()))?;
+
+        if !hex.is_ascii() {
+            return Err(ParseColorError::InvalidFormat(s.to_string()));
+        }
+
+        let expand_digit = |c: u8| -> Result<u8, ParseColorError> {
+            let d = (c as char)
+                .to_digit(16)
+                .ok_or_else(|| ParseColorError::InvalidFormat(s.to_string()))?;
+            Ok((d * 17) as u8)
+        };
+
+        let byte = |pair: &[u8]| -> Result<u8, ParseColorError> {
+            u8::from_str_radix(std::str::from_utf8(pair).unwrap(), 16)
+                .map_err(|_| ParseColorError::InvalidFormat(s.to_string()))
+        };
+
+        let hex = hex.as_bytes();
+
+        let (r, g, b) = match hex.len() {
+            3 => (
+                expand_digit(hex[0])?,
+                expand_digit(hex[1])?,
+                expand_digit(hex[2])?,
+            ),
+            6 => (byte(&hex[0..2])?, byte(&hex[2..4])?, byte(&hex[4..6])?),
+            8 => {
+                byte(&hex[6..8])?;
+                (byte(&hex[0..2])?, byte(&hex[2..4])?, byte(&hex[4..6])?)
+            }
+            _ => return Err(ParseColorError::InvalidFormat(s.to_string())),
+        };
+
+        Ok(rgb_bytes(r, g, b))
+    }
+
+    /// Format as a `#rrggbb` hex string, rounding each channel to the
+    /// nearest 8-bit value (matching the `+ 0.5` rounding csscolorparser
+    /// uses for `to_rgba8`).
+    pub fn to_hex(&self) -> String {
+        let Rgb([r, g, b]) = self.rgb_srgb();
+
+        format!(
+            "#{:02x}{:02x}{:02x}",
+            (r.to_f64() * 255.0 + 0.5) as u8,
+            (g.to_f64() * 255.0 + 0.5) as u8,
+            (b.to_f64() * 255.0 + 0.5) as u8,
+        )
+    }
+
+    /// Parse a broader set of CSS color syntaxes: `#rgb`, `#rrggbb`,
+    /// `#rrggbbaa`, the `rgb()`/`rgba()` functional notations and, with the
+    /// `named-colors` feature enabled, W3C named colors.
+    pub fn from_css(s: &str) -> Result<Ryb<T>, ParseColorError> {
+        let s = s.trim();
+
+        if s.starts_with('#') {
+            return Ryb::from_hex(s);
+        }
+
+        if let Some(args) = s.strip_prefix("rgba(").or_else(|| s.strip_prefix("rgb(")) {
+            let args = args
+                .strip_suffix(')')
+                .ok_or_else(|| ParseColorError::InvalidFormat(s.to_string()))?;
+
+            let channel = |part: Option<&str>| -> Result<u8, ParseColorError> {
+                let part = part
+                    .map(str::trim)
+                    .ok_or_else(|| ParseColorError::InvalidFormat(s.to_string()))?;
+                part.parse::<u8>()
+                    .map_err(|_| ParseColorError::InvalidChannel(part.to_string()))
+            };
+
+            let mut parts = args.split(',');
+            let r = channel(parts.next())?;
+            let g = channel(parts.next())?;
+            let b = channel(parts.next())?;
+
+            return Ok(rgb_bytes(r, g, b));
+        }
+
+        #[cfg(feature = "named-colors")]
+        if let Some((r, g, b)) = named_colors::lookup(s) {
+            return Ok(rgb_bytes(r, g, b));
+        }
+
+        Err(ParseColorError::InvalidFormat(s.to_string()))
+    }
+}
+
+/// The W3C/CSS extended named color table, gated behind the `named-colors`
+/// feature since it is sizeable and many users only ever need hex or
+/// functional notation.
+#[cfg(feature = "named-colors")]
+mod named_colors {
+    /// Look up a CSS named color (case-insensitively) and return its `(r,
+    /// g, b)` bytes.
+    pub(super) fn lookup(name: &str) -> Option<(u8, u8, u8)> {
+        let rgb = match name.to_ascii_lowercase().as_str() {
+            "aliceblue" => (0xf0, 0xf8, 0xff),
+            "antiquewhite" => (0xfa, 0xeb, 0xd7),
+            "aqua" => (0x00, 0xff, 0xff),
+            "aquamarine" => (0x7f, 0xff, 0xd4),
+            "azure" => (0xf0, 0xff, 0xff),
+            "beige" => (0xf5, 0xf5, 0xdc),
+            "bisque" => (0xff, 0xe4, 0xc4),
+            "black" => (0x00, 0x00, 0x00),
+            "blanchedalmond" => (0xff, 0xeb, 0xcd),
+            "blue" => (0x00, 0x00, 0xff),
+            "blueviolet" => (0x8a, 0x2b, 0xe2),
+            "brown" => (0xa5, 0x2a, 0x2a),
+            "burlywood" => (0xde, 0xb8, 0x87),
+            "cadetblue" => (0x5f, 0x9e, 0xa0),
+            "chartreuse" => (0x7f, 0xff, 0x00),
+            "chocolate" => (0xd2, 0x69, 0x1e),
+            "coral" => (0xff, 0x7f, 0x50),
+            "cornflowerblue" => (0x64, 0x95, 0xed),
+            "cornsilk" => (0xff, 0xf8, 0xdc),
+            "crimson" => (0xdc, 0x14, 0x3c),
+            "cyan" => (0x00, 0xff, 0xff),
+            "darkblue" => (0x00, 0x00, 0x8b),
+            "darkcyan" => (0x00, 0x8b, 0x8b),
+            "darkgoldenrod" => (0xb8, 0x86, 0x0b),
+            "darkgray" | "darkgrey" => (0xa9, 0xa9, 0xa9),
+            "darkgreen" => (0x00, 0x64, 0x00),
+            "darkkhaki" => (0xbd, 0xb7, 0x6b),
+            "darkmagenta" => (0x8b, 0x00, 0x8b),
+            "darkolivegreen" => (0x55, 0x6b, 0x2f),
+            "darkorange" => (0xff, 0x8c, 0x00),
+            "darkorchid" => (0x99, 0x32, 0xcc),
+            "darkred" => (0x8b, 0x00, 0x00),
+            "darksalmon" => (0xe9, 0x96, 0x7a),
+            "darkseagreen" => (0x8f, 0xbc, 0x8f),
+            "darkslateblue" => (0x48, 0x3d, 0x8b),
+            "darkslategray" | "darkslategrey" => (0x2f, 0x4f, 0x4f),
+            "darkturquoise" => (0x00, 0xce, 0xd1),
+            "darkviolet" => (0x94, 0x00, 0xd3),
+            "deeppink" => (0xff, 0x14, 0x93),
+            "deepskyblue" => (0x00, 0xbf, 0xff),
+            "dimgray" | "dimgrey" => (0x69, 0x69, 0x69),
+            "dodgerblue" => (0x1e, 0x90, 0xff),
+            "firebrick" => (0xb2, 0x22, 0x22),
+            "floralwhite" => (0xff, 0xfa, 0xf0),
+            "forestgreen" => (0x22, 0x8b, 0x22),
+            "fuchsia" => (0xff, 0x00, 0xff),
+            "gainsboro" => (0xdc, 0xdc, 0xdc),
+            "ghostwhite" => (0xf8, 0xf8, 0xff),
+            "gold" => (0xff, 0xd7, 0x00),
+            "goldenrod" => (0xda, 0xa5, 0x20),
+            "gray" | "grey" => (0x80, 0x80, 0x80),
+            "green" => (0x00, 0x80, 0x00),
+            "greenyellow" => (0xad, 0xff, 0x2f),
+            "honeydew" => (0xf0, 0xff, 0xf0),
+            "hotpink" => (0xff, 0x69, 0xb4),
+            "indianred" => (0xcd, 0x5c, 0x5c),
+            "indigo" => (0x4b, 0x00, 0x82),
+            "ivory" => (0xff, 0xff, 0xf0),
+            "khaki" => (0xf0, 0xe6, 0x8c),
+            "lavender" => (0xe6, 0xe6, 0xfa),
+            "lavenderblush" => (0xff, 0xf0, 0xf5),
+            "lawngreen" => (0x7c, 0xfc, 0x00),
+            "lemonchiffon" => (0xff, 0xfa, 0xcd),
+            "lightblue" => (0xad, 0xd8, 0xe6),
+            "lightcoral" => (0xf0, 0x80, 0x80),
+            "lightcyan" => (0xe0, 0xff, 0xff),
+            "lightgoldenrodyellow" => (0xfa, 0xfa, 0xd2),
+            "lightgray" | "lightgrey" => (0xd3, 0xd3, 0xd3),
+            "lightgreen" => (0x90, 0xee, 0x90),
+            "lightpink" => (0xff, 0xb6, 0xc1),
+            "lightsalmon" => (0xff, 0xa0, 0x7a),
+            "lightseagreen" => (0x20, 0xb2, 0xaa),
+            "lightskyblue" => (0x87, 0xce, 0xfa),
+            "lightslategray" | "lightslategrey" => (0x77, 0x88, 0x99),
+            "lightsteelblue" => (0xb0, 0xc4, 0xde),
+            "lightyellow" => (0xff, 0xff, 0xe0),
+            "lime" => (0x00, 0xff, 0x00),
+            "limegreen" => (0x32, 0xcd, 0x32),
+            "linen" => (0xfa, 0xf0, 0xe6),
+            "magenta" => (0xff, 0x00, 0xff),
+            "maroon" => (0x80, 0x00, 0x00),
+            "mediumaquamarine" => (0x66, 0xcd, 0xaa),
+            "mediumblue" => (0x00, 0x00, 0xcd),
+            "mediumorchid" => (0xba, 0x55, 0xd3),
+            "mediumpurple" => (0x93, 0x70, 0xdb),
+            "mediumseagreen" => (0x3c, 0xb3, 0x71),
+            "mediumslateblue" => (0x7b, 0x68, 0xee),
+            "mediumspringgreen" => (0x00, 0xfa, 0x9a),
+            "mediumturquoise" => (0x48, 0xd1, 0xcc),
+            "mediumvioletred" => (0xc7, 0x15, 0x85),
+            "midnightblue" => (0x19, 0x19, 0x70),
+            "mintcream" => (0xf5, 0xff, 0xfa),
+            "mistyrose" => (0xff, 0xe4, 0xe1),
+            "moccasin" => (0xff, 0xe4, 0xb5),
+            "navajowhite" => (0xff, 0xde, 0xad),
+            "navy" => (0x00, 0x00, 0x80),
+            "oldlace" => (0xfd, 0xf5, 0xe6),
+            "olive" => (0x80, 0x80, 0x00),
+            "olivedrab" => (0x6b, 0x8e, 0x23),
+            "orange" => (0xff, 0xa5, 0x00),
+            "orangered" => (0xff, 0x45, 0x00),
+            "orchid" => (0xda, 0x70, 0xd6),
+            "palegoldenrod" => (0xee, 0xe8, 0xaa),
+            "palegreen" => (0x98, 0xfb, 0x98),
+            "paleturquoise" => (0xaf, 0xee, 0xee),
+            "palevioletred" => (0xdb, 0x70, 0x93),
+            "papayawhip" => (0xff, 0xef, 0xd5),
+            "peachpuff" => (0xff, 0xda, 0xb9),
+            "peru" => (0xcd, 0x85, 0x3f),
+            "pink" => (0xff, 0xc0, 0xcb),
+            "plum" => (0xdd, 0xa0, 0xdd),
+            "powderblue" => (0xb0, 0xe0, 0xe6),
+            "purple" => (0x80, 0x00, 0x80),
+            "rebeccapurple" => (0x66, 0x33, 0x99),
+            "red" => (0xff, 0x00, 0x00),
+            "rosybrown" => (0xbc, 0x8f, 0x8f),
+            "royalblue" => (0x41, 0x69, 0xe1),
+            "saddlebrown" => (0x8b, 0x45, 0x13),
+            "salmon" => (0xfa, 0x80, 0x72),
+            "sandybrown" => (0xf4, 0xa4, 0x60),
+            "seagreen" => (0x2e, 0x8b, 0x57),
+            "seashell" => (0xff, 0xf5, 0xee),
+            "sienna" => (0xa0, 0x52, 0x2d),
+            "silver" => (0xc0, 0xc0, 0xc0),
+            "skyblue" => (0x87, 0xce, 0xeb),
+            "slateblue" => (0x6a, 0x5a, 0xcd),
+            "slategray" | "slategrey" => (0x70, 0x80, 0x90),
+            "snow" => (0xff, 0xfa, 0xfa),
+            "springgreen" => (0x00, 0xff, 0x7f),
+            "steelblue" => (0x46, 0x82, 0xb4),
+            "tan" => (0xd2, 0xb4, 0x8c),
+            "teal" => (0x00, 0x80, 0x80),
+            "thistle" => (0xd8, 0xbf, 0xd8),
+            "tomato" => (0xff, 0x63, 0x47),
+            "turquoise" => (0x40, 0xe0, 0xd0),
+            "violet" => (0xee, 0x82, 0xee),
+            "wheat" => (0xf5, 0xde, 0xb3),
+            "white" => (0xff, 0xff, 0xff),
+            "whitesmoke" => (0xf5, 0xf5, 0xf5),
+            "yellow" => (0xff, 0xff, 0x00),
+            "yellowgreen" => (0x9a, 0xcd, 0x32),
+            _ => return None,
+        };
+
+        Some(rgb)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex_of(s: &str) -> String {
+        Ryb::<f64>::from_hex(s).unwrap().to_hex()
+    }
+
+    fn hex_of_css(s: &str) -> String {
+        Ryb::<f64>::from_css(s).unwrap().to_hex()
+    }
+
+    #[test]
+    fn from_hex_parses_all_lengths() {
+        assert_eq!(hex_of("#f00"), hex_of("#ff0000"));
+        assert_eq!(hex_of("#00ff80"), hex_of("#00ff80ff"));
+    }
+
+    #[test]
+    fn from_hex_rejects_bad_input() {
+        assert!(Ryb::<f64>::from_hex("ff0000").is_err());
+        assert!(Ryb::<f64>::from_hex("#ff00").is_err());
+        assert!(Ryb::<f64>::from_hex("#gg0000").is_err());
+        assert!(Ryb::<f64>::from_hex("#ff0000zz").is_err());
+    }
+
+    #[test]
+    fn to_hex_round_trips_through_from_hex() {
+        assert_eq!(hex_of("#ff0000"), "#ff0000");
+    }
+
+    #[test]
+    fn from_css_parses_hex_and_functional_notation() {
+        assert_eq!(hex_of_css("#ff0000"), hex_of_css(" rgb(255, 0, 0) "));
+        assert_eq!(
+            hex_of_css("rgb(0, 128, 255)"),
+            hex_of_css("rgba(0, 128, 255, 1)"),
+        );
+    }
+
+    #[test]
+    fn from_css_rejects_unknown_syntax() {
+        assert!(matches!(
+            Ryb::<f64>::from_css("not-a-color"),
+            Err(ParseColorError::InvalidFormat(_)),
+        ));
+        assert!(matches!(
+            Ryb::<f64>::from_css("rgb(256, 0, 0)"),
+            Err(ParseColorError::InvalidChannel(_)),
+        ));
+    }
+
+    #[cfg(feature = "named-colors")]
+    #[test]
+    fn from_css_looks_up_named_colors() {
+        assert_eq!(hex_of_css("Red"), hex_of_css("#ff0000"));
+        assert!(Ryb::<f64>::from_css("notacolorname").is_err());
+    }
+}
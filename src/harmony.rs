@@ -0,0 +1,139 @@
+//! Harmony schemes on the RYB color wheel.
+
+use crate::color::{Component, Ryb};
+
+/// Decompose a color into RYB hue, saturation and value, mirroring the
+/// standard HSV decomposition of RGB but with `y` and `b` playing the role
+/// of `g` and `b` respectively. Hue is in degrees, `[0, 360)`.
+fn to_hsv<T: Component>(color: &Ryb<T>) -> (f64, f64, f64) {
+    let Ryb([r, y, b]) = *color;
+    let r = r.to_f64();
+    let y = y.to_f64();
+    let b = b.to_f64();
+
+    let max = f64::max(f64::max(r, y), b);
+    let min = f64::min(f64::min(r, y), b);
+    let delta = max - min;
+
+    let hue = if delta == 0.0 {
+        0.0
+    } else if max == r {
+        60.0 * ((y - b) / delta).rem_euclid(6.0)
+    } else if max == y {
+        60.0 * ((b - r) / delta + 2.0)
+    } else {
+        60.0 * ((r - y) / delta + 4.0)
+    };
+
+    let saturation = if max == 0.0 { 0.0 } else { delta / max };
+
+    (hue.rem_euclid(360.0), saturation, max)
+}
+
+/// The inverse of [`to_hsv`]: rebuild a color from RYB hue, saturation and
+/// value.
+fn from_hsv<T: Component>(hue: f64, saturation: f64, value: f64) -> Ryb<T> {
+    let hue = hue.rem_euclid(360.0);
+    let c = value * saturation;
+    let x = c * (1.0 - f64::abs((hue / 60.0).rem_euclid(2.0) - 1.0));
+    let m = value - c;
+
+    let (r, y, b) = if hue < 60.0 {
+        (c, x, 0.0)
+    } else if hue < 120.0 {
+        (x, c, 0.0)
+    } else if hue < 180.0 {
+        (0.0, c, x)
+    } else if hue < 240.0 {
+        (0.0, x, c)
+    } else if hue < 300.0 {
+        (x, 0.0, c)
+    } else {
+        (c, 0.0, x)
+    };
+
+    Ryb([
+        Component::from_f64((r + m).clamp(0.0, 1.0)),
+        Component::from_f64((y + m).clamp(0.0, 1.0)),
+        Component::from_f64((b + m).clamp(0.0, 1.0)),
+    ])
+}
+
+/// Rotate a color's hue around the RYB wheel by `angle_deg`, preserving its
+/// saturation and value.
+fn rotate<T: Component>(color: &Ryb<T>, angle_deg: f64) -> Ryb<T> {
+    let (hue, saturation, value) = to_hsv(color);
+    from_hsv(hue + angle_deg, saturation, value)
+}
+
+impl<T: Component> Ryb<T> {
+    /// The color directly opposite `self` on the RYB wheel.
+    pub fn complement(&self) -> Ryb<T> {
+        rotate(self, 180.0)
+    }
+
+    /// The three colors of a triadic scheme: `self` and its two neighbors,
+    /// each 120° apart on the RYB wheel.
+    pub fn triadic(&self) -> [Ryb<T>; 3] {
+        [rotate(self, 0.0), rotate(self, 120.0), rotate(self, 240.0)]
+    }
+
+    /// `count` colors spaced `angle_deg` apart on the RYB wheel, centered on
+    /// `self`.
+    pub fn analogous(&self, angle_deg: f64, count: usize) -> Vec<Ryb<T>> {
+        let offset = (count as f64 - 1.0) / 2.0;
+        (0..count)
+            .map(|i| rotate(self, (i as f64 - offset) * angle_deg))
+            .collect()
+    }
+
+    /// A split-complementary scheme: `self` plus the two colors adjacent to
+    /// its complement.
+    pub fn split_complementary(&self) -> [Ryb<T>; 3] {
+        [rotate(self, 0.0), rotate(self, 150.0), rotate(self, 210.0)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::color::{BLUE, RED, YELLOW};
+
+    fn assert_close(a: Ryb<f64>, b: Ryb<f64>) {
+        let Ryb([r1, y1, b1]) = a;
+        let Ryb([r2, y2, b2]) = b;
+        assert!((r1 - r2).abs() < 1e-9);
+        assert!((y1 - y2).abs() < 1e-9);
+        assert!((b1 - b2).abs() < 1e-9);
+    }
+
+    #[test]
+    fn complement_of_primaries() {
+        assert_close(RED.complement(), Ryb([0.0, 1.0, 1.0]));
+        assert_close(BLUE.complement(), Ryb([1.0, 1.0, 0.0]));
+        assert_close(YELLOW.complement(), Ryb([1.0, 0.0, 1.0]));
+    }
+
+    #[test]
+    fn triadic_of_red_is_the_three_primaries() {
+        let [a, b, c] = RED.triadic();
+        assert_close(a, RED);
+        assert_close(b, YELLOW);
+        assert_close(c, BLUE);
+    }
+
+    #[test]
+    fn analogous_is_centered_on_self() {
+        let colors = RED.analogous(30.0, 3);
+        assert_eq!(colors.len(), 3);
+        assert_close(colors[1], RED);
+    }
+
+    #[test]
+    fn split_complementary_flanks_the_complement() {
+        let [a, b, c] = RED.split_complementary();
+        assert_close(a, RED);
+        assert_close(b, Ryb([0.0, 1.0, 0.5]));
+        assert_close(c, Ryb([0.0, 0.5, 1.0]));
+    }
+}
@@ -0,0 +1,4 @@
+pub mod color;
+pub mod css;
+pub mod harmony;
+pub mod pixel;
@@ -0,0 +1,183 @@
+//! [`image::Pixel`] support for [`Ryb`].
+
+use image::{Luma, LumaA, Pixel, Rgb, Rgba};
+
+use crate::color::{mix, Component, Ryb};
+
+impl<T: Component> Pixel for Ryb<T> {
+    type Subpixel = T;
+
+    const CHANNEL_COUNT: u8 = 3;
+
+    const COLOR_MODEL: &'static str = "RYB";
+
+    fn channels(&self) -> &[T] {
+        &self.0
+    }
+
+    fn channels_mut(&mut self) -> &mut [T] {
+        &mut self.0
+    }
+
+    fn channels4(&self) -> (T, T, T, T) {
+        let Ryb([r, y, b]) = *self;
+        (r, y, b, T::DEFAULT_MAX_VALUE)
+    }
+
+    fn from_channels(r: T, y: T, b: T, _a: T) -> Ryb<T> {
+        Ryb([r, y, b])
+    }
+
+    fn from_slice(slice: &[T]) -> &Ryb<T> {
+        assert_eq!(slice.len(), Self::CHANNEL_COUNT as usize);
+        unsafe { &*(slice.as_ptr() as *const Ryb<T>) }
+    }
+
+    fn from_slice_mut(slice: &mut [T]) -> &mut Ryb<T> {
+        assert_eq!(slice.len(), Self::CHANNEL_COUNT as usize);
+        unsafe { &mut *(slice.as_mut_ptr() as *mut Ryb<T>) }
+    }
+
+    // `image`'s own `Pixel` impls assume their channels are gamma-encoded
+    // sRGB, so these go through `rgb_srgb` rather than the linear `rgb`.
+    fn to_rgb(&self) -> Rgb<T> {
+        self.rgb_srgb()
+    }
+
+    fn to_rgba(&self) -> Rgba<T> {
+        let Rgb([r, g, b]) = self.rgb_srgb();
+        Rgba([r, g, b, T::DEFAULT_MAX_VALUE])
+    }
+
+    fn to_luma(&self) -> Luma<T> {
+        let Rgb([r, g, b]) = self.rgb_srgb();
+        let l = 0.2126 * r.to_f64() + 0.7152 * g.to_f64() + 0.0722 * b.to_f64();
+        Luma([Component::from_f64(l.clamp(0.0, 1.0))])
+    }
+
+    fn to_luma_alpha(&self) -> LumaA<T> {
+        let Luma([l]) = self.to_luma();
+        LumaA([l, T::DEFAULT_MAX_VALUE])
+    }
+
+    fn map<F>(&self, mut f: F) -> Ryb<T>
+    where
+        F: FnMut(T) -> T,
+    {
+        let Ryb([r, y, b]) = *self;
+        Ryb([f(r), f(y), f(b)])
+    }
+
+    fn apply<F>(&mut self, mut f: F)
+    where
+        F: FnMut(T) -> T,
+    {
+        for c in self.channels_mut() {
+            *c = f(*c);
+        }
+    }
+
+    fn map_with_alpha<F, G>(&self, f: F, _g: G) -> Ryb<T>
+    where
+        F: FnMut(T) -> T,
+        G: FnMut(T) -> T,
+    {
+        self.map(f)
+    }
+
+    fn apply_with_alpha<F, G>(&mut self, f: F, _g: G)
+    where
+        F: FnMut(T) -> T,
+        G: FnMut(T) -> T,
+    {
+        self.apply(f)
+    }
+
+    fn map2<F>(&self, other: &Ryb<T>, mut f: F) -> Ryb<T>
+    where
+        F: FnMut(T, T) -> T,
+    {
+        let Ryb([r1, y1, b1]) = *self;
+        let Ryb([r2, y2, b2]) = *other;
+        Ryb([f(r1, r2), f(y1, y2), f(b1, b2)])
+    }
+
+    fn apply2<F>(&mut self, other: &Ryb<T>, f: F)
+    where
+        F: FnMut(T, T) -> T,
+    {
+        *self = self.map2(other, f);
+    }
+
+    /// Blend `other` onto `self` by subtractive RYB mixing (an equal-weight
+    /// [`mix`] of the two pigments), rather than RGB alpha compositing —
+    /// `Ryb` has no alpha channel to composite against.
+    fn blend(&mut self, other: &Ryb<T>) {
+        let one = T::from_f64(1.0);
+        *self = mix(vec![(one, *self), (one, *other)]);
+    }
+
+    fn invert(&mut self) {
+        self.apply(|c| T::from_f64(1.0 - c.to_f64()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::color::{BLUE, RED};
+
+    #[test]
+    fn from_slice_views_a_channel_buffer_in_place() {
+        let mut buf = [0.2_f64, 0.4, 0.6];
+        assert_eq!(Ryb::from_slice(&buf).0, [0.2, 0.4, 0.6]);
+
+        Ryb::from_slice_mut(&mut buf).0[1] = 0.9;
+        assert_eq!(buf, [0.2, 0.9, 0.6]);
+    }
+
+    #[test]
+    fn map_and_apply_transform_every_channel() {
+        let color = Ryb([0.1_f64, 0.2, 0.3]);
+        assert_eq!(color.map(|c| c * 2.0).0, [0.2, 0.4, 0.6]);
+
+        let mut color = color;
+        color.apply(|c| c * 2.0);
+        assert_eq!(color.0, [0.2, 0.4, 0.6]);
+    }
+
+    #[test]
+    fn map2_and_apply2_combine_channelwise() {
+        let a = Ryb([0.1_f64, 0.2, 0.3]);
+        let b = Ryb([1.0_f64, 1.0, 1.0]);
+        assert_eq!(a.map2(&b, |x, y| x + y).0, [1.1, 1.2, 1.3]);
+
+        let mut a = a;
+        a.apply2(&b, |x, y| x + y);
+        assert_eq!(a.0, [1.1, 1.2, 1.3]);
+    }
+
+    #[test]
+    fn invert_complements_every_channel() {
+        let mut color = Ryb([0.2_f64, 0.4, 1.0]);
+        color.invert();
+        assert_eq!(color.0, [0.8, 0.6, 0.0]);
+    }
+
+    #[test]
+    fn blend_mixes_pigments_evenly() {
+        let mut color = RED;
+        color.blend(&BLUE);
+        let Ryb([r, y, b]) = color;
+        assert!((r - 0.5).abs() < 1e-9);
+        assert!(y.abs() < 1e-9);
+        assert!((b - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn to_rgb_uses_srgb_encoding() {
+        let Rgb([r, g, b]) = RED.to_rgb();
+        let Rgb([r_srgb, g_srgb, b_srgb]) = RED.rgb_srgb();
+        assert_eq!((r, g, b), (r_srgb, g_srgb, b_srgb));
+    }
+}